@@ -1 +1,9 @@
-// Nothing to see here (yet), see `dev` branch
+// The device API lives on the `dev` branch; only the shared attribute types are here so far.
+
+pub mod lo;
+pub mod modes;
+
+pub use lo::{LoLimits, ParseLoLimitsError};
+pub use modes::{
+    Available, CalibMode, ENSMMode, GainControlMode, ParseModeError, RxPortSelect, TxPortSelect,
+};
@@ -0,0 +1,139 @@
+//! LO tuning limits as reported by the `ad9361-phy` driver.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Error returned when a `frequency_available` value is not `[min step max]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLoLimitsError {
+    value: String,
+}
+
+impl ParseLoLimitsError {
+    /// The string that failed to parse.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseLoLimitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid LO frequency range: {:?}", self.value)
+    }
+}
+
+impl std::error::Error for ParseLoLimitsError {}
+
+/// Tunable LO span parsed from the LO channel's `frequency_available` attribute.
+///
+/// [`FromStr`] accepts the driver format `[min step max]` (in Hz) and
+/// [`Display`](fmt::Display) writes it back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoLimits {
+    min: u64,
+    step: u64,
+    max: u64,
+}
+
+impl LoLimits {
+    /// Lowest tunable frequency in Hz.
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    /// Tuning step in Hz.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Highest tunable frequency in Hz.
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// Tunable frequencies in Hz.
+    pub fn range(&self) -> RangeInclusive<u64> {
+        self.min..=self.max
+    }
+
+    /// Returns `true` if `freq` lies within the span and on the step grid.
+    pub fn contains(&self, freq: u64) -> bool {
+        self.range().contains(&freq) && (freq - self.min).is_multiple_of(self.step)
+    }
+}
+
+impl fmt::Display for LoLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {} {}]", self.min, self.step, self.max)
+    }
+}
+
+impl FromStr for LoLimits {
+    type Err = ParseLoLimitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let err = || ParseLoLimitsError {
+            value: s.to_owned(),
+        };
+
+        let inner = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(err)?;
+        let mut fields = inner.split_whitespace().map(str::parse::<u64>);
+        let (Some(Ok(min)), Some(Ok(step)), Some(Ok(max)), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(err());
+        };
+        if step == 0 || min > max {
+            return Err(err());
+        }
+
+        Ok(Self { min, step, max })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_driver_format() {
+        let limits: LoLimits = "[70000000 1 6000000000]\n".parse().unwrap();
+        assert_eq!(limits.min(), 70_000_000);
+        assert_eq!(limits.step(), 1);
+        assert_eq!(limits.max(), 6_000_000_000);
+        assert_eq!(limits.to_string(), "[70000000 1 6000000000]");
+    }
+
+    #[test]
+    fn contains_checks_span_and_step() {
+        let limits: LoLimits = "[100 10 200]".parse().unwrap();
+        assert!(limits.contains(100));
+        assert!(limits.contains(150));
+        assert!(limits.contains(200));
+        assert!(!limits.contains(155));
+        assert!(!limits.contains(90));
+        assert!(!limits.contains(210));
+    }
+
+    #[test]
+    fn rejects_malformed_values() {
+        for bad in [
+            "70000000 1 6000000000",
+            "[70000000 1]",
+            "[70000000 1 6000000000 4]",
+            "[70000000 x 6000000000]",
+            "[70000000 0 6000000000]",
+            "[6000000000 1 70000000]",
+        ] {
+            assert!(bad.parse::<LoLimits>().is_err(), "{bad}");
+        }
+
+        let err = "[1 2]\n".parse::<LoLimits>().unwrap_err();
+        assert_eq!(err.value(), "[1 2]");
+    }
+}
@@ -0,0 +1,315 @@
+//! Typed representations of the string-valued AD9361 PHY attributes.
+//!
+//! Every enum round-trips the exact strings used by the `ad9361-phy` driver:
+//! [`Display`](fmt::Display) produces the value to write into the attribute and
+//! [`FromStr`] parses the value read back from it.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when a string is not a known value of a mode enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseModeError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseModeError {
+    /// Name of the enum the value was parsed into.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// The string that failed to parse.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} value: {:?}", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ParseModeError {}
+
+/// Values listed in a space-separated `*_available` attribute.
+///
+/// Tokens this crate does not know, e.g. modes added by a newer driver, are kept
+/// in [`unrecognized`](Self::unrecognized) instead of failing the whole list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Available<T> {
+    values: Vec<T>,
+    unrecognized: Vec<String>,
+}
+
+impl<T: PartialEq> Available<T> {
+    /// Known values, in the order the driver listed them.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Tokens that did not parse into a known value.
+    pub fn unrecognized(&self) -> &[String] {
+        &self.unrecognized
+    }
+
+    /// Returns `true` if the driver listed `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.values.contains(value)
+    }
+}
+
+macro_rules! driver_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $name {
+            /// Every value, in driver order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)+];
+
+            /// Iterates over every value, in driver order.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
+
+            /// Parses a space-separated `*_available` attribute value, e.g.
+            /// `gain_control_mode_available`, into the values this chip supports.
+            pub fn parse_available(s: &str) -> Available<Self> {
+                let mut available = Available {
+                    values: Vec::new(),
+                    unrecognized: Vec::new(),
+                };
+                for token in s.split_whitespace() {
+                    match token.parse() {
+                        Ok(value) => available.values.push(value),
+                        Err(_) => available.unrecognized.push(token.to_owned()),
+                    }
+                }
+                available
+            }
+
+            /// Returns the driver string for this value.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseModeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                match s {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(ParseModeError {
+                        kind: stringify!($name),
+                        value: s.to_owned(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+driver_enum! {
+    /// Enable State Machine mode (`ensm_mode`).
+    #[non_exhaustive]
+    pub enum ENSMMode {
+        Sleep => "sleep",
+        Wait => "wait",
+        Alert => "alert",
+        Rx => "rx",
+        Tx => "tx",
+        Fdd => "fdd",
+        PinCtrl => "pinctrl",
+        PinCtrlFddIndep => "pinctrl_fdd_indep",
+    }
+}
+
+driver_enum! {
+    /// Calibration mode (`calib_mode`).
+    #[non_exhaustive]
+    pub enum CalibMode {
+        Auto => "auto",
+        Manual => "manual",
+        ManualTxQuad => "manual_tx_quad",
+        TxQuad => "tx_quad",
+        RfDcOffs => "rf_dc_offs",
+        RssiGainStep => "rssi_gain_step",
+    }
+}
+
+driver_enum! {
+    /// RX gain control mode (`gain_control_mode`).
+    #[non_exhaustive]
+    pub enum GainControlMode {
+        Manual => "manual",
+        FastAttack => "fast_attack",
+        SlowAttack => "slow_attack",
+        Hybrid => "hybrid",
+    }
+}
+
+driver_enum! {
+    /// RX input port (`rf_port_select` of the RX channels).
+    pub enum RxPortSelect {
+        ABalanced => "A_BALANCED",
+        BBalanced => "B_BALANCED",
+        CBalanced => "C_BALANCED",
+        AN => "A_N",
+        AP => "A_P",
+        BN => "B_N",
+        BP => "B_P",
+        CN => "C_N",
+        CP => "C_P",
+        TxMonitor1 => "TX_MONITOR1",
+        TxMonitor2 => "TX_MONITOR2",
+        TxMonitor1_2 => "TX_MONITOR1_2",
+    }
+}
+
+driver_enum! {
+    /// TX output port (`rf_port_select` of the TX channels).
+    pub enum TxPortSelect {
+        A => "A",
+        B => "B",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip<T>(all: &[T])
+    where
+        T: Copy + fmt::Debug + fmt::Display + FromStr<Err = ParseModeError> + PartialEq,
+    {
+        for &value in all {
+            assert_eq!(value.to_string().parse::<T>(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn round_trip_every_variant() {
+        assert_round_trip(ENSMMode::ALL);
+        assert_round_trip(CalibMode::ALL);
+        assert_round_trip(GainControlMode::ALL);
+        assert_round_trip(RxPortSelect::ALL);
+        assert_round_trip(TxPortSelect::ALL);
+    }
+
+    fn driver_strings<T: fmt::Display>(all: &[T]) -> Vec<String> {
+        all.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn all_lists_every_variant_once_in_driver_order() {
+        assert_eq!(
+            driver_strings(ENSMMode::ALL),
+            [
+                "sleep",
+                "wait",
+                "alert",
+                "rx",
+                "tx",
+                "fdd",
+                "pinctrl",
+                "pinctrl_fdd_indep"
+            ]
+        );
+        assert_eq!(
+            driver_strings(CalibMode::ALL),
+            [
+                "auto",
+                "manual",
+                "manual_tx_quad",
+                "tx_quad",
+                "rf_dc_offs",
+                "rssi_gain_step"
+            ]
+        );
+        assert_eq!(
+            driver_strings(GainControlMode::ALL),
+            ["manual", "fast_attack", "slow_attack", "hybrid"]
+        );
+        assert_eq!(
+            driver_strings(RxPortSelect::ALL),
+            [
+                "A_BALANCED",
+                "B_BALANCED",
+                "C_BALANCED",
+                "A_N",
+                "A_P",
+                "B_N",
+                "B_P",
+                "C_N",
+                "C_P",
+                "TX_MONITOR1",
+                "TX_MONITOR2",
+                "TX_MONITOR1_2",
+            ]
+        );
+        assert_eq!(driver_strings(TxPortSelect::ALL), ["A", "B"]);
+        assert!(GainControlMode::iter().eq(GainControlMode::ALL.iter().copied()));
+    }
+
+    #[test]
+    fn from_str_trims_attribute_newline() {
+        assert_eq!("fast_attack\n".parse(), Ok(GainControlMode::FastAttack));
+        assert_eq!("TX_MONITOR1_2\n".parse(), Ok(RxPortSelect::TxMonitor1_2));
+    }
+
+    #[test]
+    fn parse_available_keeps_unknown_tokens() {
+        let available =
+            GainControlMode::parse_available("manual fast_attack slow_attack hybrid turbo\n");
+        assert_eq!(
+            available.values(),
+            [
+                GainControlMode::Manual,
+                GainControlMode::FastAttack,
+                GainControlMode::SlowAttack,
+                GainControlMode::Hybrid,
+            ]
+        );
+        assert_eq!(available.unrecognized(), ["turbo"]);
+        assert!(available.contains(&GainControlMode::Hybrid));
+
+        let available = GainControlMode::parse_available("manual fast_attack slow_attack");
+        assert!(!available.contains(&GainControlMode::Hybrid));
+        assert!(available.unrecognized().is_empty());
+    }
+
+    #[test]
+    fn unknown_value_is_reported() {
+        let err = "foo\n".parse::<CalibMode>().unwrap_err();
+        assert_eq!(err.kind(), "CalibMode");
+        assert_eq!(err.value(), "foo");
+        assert_eq!(err.to_string(), "unknown CalibMode value: \"foo\"");
+    }
+}